@@ -0,0 +1,241 @@
+//! Push-style streaming of CCI signal updates.
+//!
+//! Watches the three JSONL files under `~/.hymetalab/shared/cci-bus/` for appends and
+//! emits a `cci-signal` event carrying just the newly-appended signal, instead of making
+//! the frontend poll and re-read each file from the top every time. `read_cci_signals`
+//! remains the initial snapshot the frontend reads before calling `start_cci_watch`.
+
+use crate::{i18n, parse_signal_from_line, CciSignal};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, State};
+
+const CCI_SIGNAL_EVENT: &str = "cci-signal";
+
+/// The files we watch, paired with the source key the frontend expects in events.
+const WATCHED_SOURCES: &[(&str, &str)] = &[
+    ("companion", "companion-signals.jsonl"),
+    ("dugout", "dugout-signals.jsonl"),
+    ("hmm", "hmm-signals.jsonl"),
+];
+
+#[derive(Clone, Serialize)]
+struct CciSignalEvent {
+    source: String,
+    signal: CciSignal,
+}
+
+/// Holds the live file watcher, if one has been started via `start_cci_watch`. Dropping
+/// the watcher (on `stop_cci_watch`, or app shutdown) stops the watch and lets the
+/// background thread exit.
+#[derive(Default)]
+pub struct CciWatchState(Mutex<Option<RecommendedWatcher>>);
+
+fn cci_bus_dir() -> Result<PathBuf, String> {
+    let home_dir = env::var("HOME")
+        .map_err(|error| i18n::t_with("error.resolve_home_failed", &[("detail", &error.to_string())]))?;
+    Ok(Path::new(&home_dir).join(".hymetalab/shared/cci-bus"))
+}
+
+/// Reads any bytes appended to `path` since `offset`, returning them along with the file
+/// position reading started from. Resets to the start if the file has shrunk, which
+/// happens on truncation or log rotation.
+fn read_appended_bytes(path: &Path, offset: u64) -> std::io::Result<(String, u64)> {
+    let mut file = fs::File::open(path)?;
+    let current_len = file.metadata()?.len();
+    let read_from = if current_len < offset { 0 } else { offset };
+
+    file.seek(SeekFrom::Start(read_from))?;
+    let mut appended = String::new();
+    file.read_to_string(&mut appended)?;
+
+    Ok((appended, read_from))
+}
+
+/// Parses each complete (newline-terminated) line in `appended` into a signal, returning
+/// them alongside how many bytes those complete lines took up. A trailing line with no
+/// terminating `\n` means a writer's append was split across two filesystem events mid-line
+/// — it's left out of both the signals and the returned count, so the caller doesn't
+/// advance its offset past it and the remainder is picked up whole on the next read instead
+/// of being dropped. Pulled out of `emit_complete_lines` so the byte-accounting can be
+/// tested without a running `AppHandle`.
+fn parse_complete_lines(appended: &str) -> (Vec<CciSignal>, usize) {
+    let mut signals = Vec::new();
+    let mut consumed = 0;
+
+    for line in appended.split_inclusive('\n') {
+        if !line.ends_with('\n') {
+            break;
+        }
+        consumed += line.len();
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(signal) = parse_signal_from_line(trimmed) {
+            signals.push(signal);
+        }
+    }
+
+    (signals, consumed)
+}
+
+/// Emits a `cci-signal` event for each complete line in `appended`, returning how many
+/// bytes those complete lines took up (see [`parse_complete_lines`]).
+fn emit_complete_lines(app: &AppHandle, source: &str, appended: &str) -> usize {
+    let (signals, consumed) = parse_complete_lines(appended);
+
+    for signal in signals {
+        let event = CciSignalEvent {
+            source: source.to_string(),
+            signal,
+        };
+        if let Err(error) = app.emit(CCI_SIGNAL_EVENT, event) {
+            log::warn!("Failed to emit {CCI_SIGNAL_EVENT} for {source}: {error}");
+        }
+    }
+
+    consumed
+}
+
+#[tauri::command]
+pub fn start_cci_watch(app: AppHandle, state: State<CciWatchState>) -> Result<(), String> {
+    let mut watcher_slot = state.0.lock().map_err(|_| i18n::t("error.watch_state_poisoned"))?;
+    if watcher_slot.is_some() {
+        return Ok(());
+    }
+
+    let bus_dir = cci_bus_dir()?;
+    fs::create_dir_all(&bus_dir)
+        .map_err(|error| i18n::t_with("error.create_cci_bus_dir_failed", &[("detail", &error.to_string())]))?;
+
+    let mut offsets: HashMap<PathBuf, u64> = HashMap::new();
+    for (_, file_name) in WATCHED_SOURCES {
+        let file_path = bus_dir.join(file_name);
+        let initial_len = fs::metadata(&file_path).map(|metadata| metadata.len()).unwrap_or(0);
+        offsets.insert(file_path, initial_len);
+    }
+
+    let (sender, receiver) = channel::<notify::Result<Event>>();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(sender)
+        .map_err(|error| i18n::t_with("error.create_cci_watcher_failed", &[("detail", &error.to_string())]))?;
+    watcher
+        .watch(&bus_dir, RecursiveMode::NonRecursive)
+        .map_err(|error| i18n::t_with("error.watch_cci_bus_dir_failed", &[("detail", &error.to_string())]))?;
+
+    let watch_app = app.clone();
+    std::thread::spawn(move || {
+        let mut offsets = offsets;
+
+        for event in receiver {
+            let Ok(event) = event else { continue };
+            if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                continue;
+            }
+
+            for changed_path in &event.paths {
+                let Some(file_name) = changed_path.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+                let Some((source, _)) = WATCHED_SOURCES
+                    .iter()
+                    .find(|(_, watched_file_name)| *watched_file_name == file_name)
+                else {
+                    continue;
+                };
+
+                let offset = offsets.get(changed_path).copied().unwrap_or(0);
+                match read_appended_bytes(changed_path, offset) {
+                    Ok((appended, read_from)) => {
+                        let consumed = emit_complete_lines(&watch_app, source, &appended);
+                        offsets.insert(changed_path.clone(), read_from + consumed as u64);
+                    }
+                    Err(error) => log::warn!("Failed to read cci-bus update for {source}: {error}"),
+                }
+            }
+        }
+    });
+
+    *watcher_slot = Some(watcher);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_cci_watch(state: State<CciWatchState>) -> Result<(), String> {
+    let mut watcher_slot = state.0.lock().map_err(|_| i18n::t("error.watch_state_poisoned"))?;
+    *watcher_slot = None;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_complete_lines, read_appended_bytes};
+    use std::fs;
+    use std::io::Write;
+
+    fn temp_file(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("hymetalab-cci-watch-test-{}-{name}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn read_appended_bytes_resets_to_start_when_file_shrinks() {
+        let path = temp_file("shrinks");
+        fs::write(&path, "short\n").unwrap();
+
+        let (appended, read_from) = read_appended_bytes(&path, 100).unwrap();
+        assert_eq!(read_from, 0);
+        assert_eq!(appended, "short\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn read_appended_bytes_continues_from_offset_when_file_grows() {
+        let path = temp_file("grows");
+        fs::write(&path, "one\n").unwrap();
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "two\n").unwrap();
+
+        let (appended, read_from) = read_appended_bytes(&path, 4).unwrap();
+        assert_eq!(read_from, 4);
+        assert_eq!(appended, "two\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn parse_complete_lines_skips_and_does_not_consume_a_trailing_partial_line() {
+        let appended = "{\"value\":1.0,\"timestamp\":\"t1\",\"label\":\"a\"}\n{\"value\":2.0,\"timestamp\":\"t2\"";
+
+        let (signals, consumed) = parse_complete_lines(appended);
+
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].label, "a");
+        // Only the first, newline-terminated line should count toward the offset advance;
+        // the dangling partial line must be left for the next read.
+        let first_line_len = appended.find('\n').unwrap() + 1;
+        assert_eq!(consumed, first_line_len);
+    }
+
+    #[test]
+    fn parse_complete_lines_skips_blank_and_unparseable_lines() {
+        let appended = "\n   \nnot json\n{\"value\":3.0,\"timestamp\":\"t3\",\"label\":\"b\"}\n";
+
+        let (signals, consumed) = parse_complete_lines(appended);
+
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].label, "b");
+        assert_eq!(consumed, appended.len());
+    }
+}
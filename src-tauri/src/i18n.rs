@@ -0,0 +1,161 @@
+//! Localization: resolves tray labels and command error text through message keys
+//! instead of hardcoded English literals.
+//!
+//! The active locale is picked at startup from a persisted override in
+//! `~/.hymetalab/config/locale.json` when one names a bundled locale, otherwise from the
+//! OS locale, falling back to English, and can be changed at runtime via `set_locale`. Catalogs are bundled JSON message maps embedded at compile
+//! time, so no locale files need to ship alongside the binary.
+
+use crate::CONFIG_DIR_RELATIVE;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+const LOCALE_CONFIG_FILE_NAME: &str = "locale.json";
+const DEFAULT_LOCALE: &str = "en";
+
+const BUNDLED_LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.json")),
+    ("es", include_str!("../locales/es.json")),
+];
+
+fn catalogs() -> &'static HashMap<String, HashMap<String, String>> {
+    static CATALOGS: OnceLock<HashMap<String, HashMap<String, String>>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        BUNDLED_LOCALES
+            .iter()
+            .map(|(locale, contents)| {
+                let messages: HashMap<String, String> = serde_json::from_str(contents).unwrap_or_default();
+                ((*locale).to_string(), messages)
+            })
+            .collect()
+    })
+}
+
+fn active_locale() -> &'static RwLock<String> {
+    static ACTIVE_LOCALE: OnceLock<RwLock<String>> = OnceLock::new();
+    ACTIVE_LOCALE.get_or_init(|| RwLock::new(resolve_startup_locale()))
+}
+
+fn locale_config_path() -> Result<PathBuf, String> {
+    let home_dir =
+        env::var("HOME").map_err(|error| t_with("error.resolve_home_failed", &[("detail", &error.to_string())]))?;
+    Ok(Path::new(&home_dir).join(CONFIG_DIR_RELATIVE).join(LOCALE_CONFIG_FILE_NAME))
+}
+
+fn read_persisted_locale() -> Option<String> {
+    let path = locale_config_path().ok()?;
+    let contents = fs::read_to_string(path).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    value.get("locale")?.as_str().map(str::to_string)
+}
+
+fn write_persisted_locale(locale: &str) -> Result<(), String> {
+    let path = locale_config_path()?;
+    if let Some(parent_dir) = path.parent() {
+        fs::create_dir_all(parent_dir)
+            .map_err(|error| t_with("error.create_config_dir_failed", &[("detail", &error.to_string())]))?;
+    }
+
+    let payload = serde_json::json!({ "locale": locale }).to_string();
+    fs::write(&path, payload)
+        .map_err(|error| t_with("error.persist_locale_failed", &[("detail", &error.to_string())]))
+}
+
+/// Collapses a BCP-47-ish tag (`es-MX`, `es_MX`) down to its base language (`es`).
+fn normalize_locale(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_ascii_lowercase()
+}
+
+fn resolve_startup_locale() -> String {
+    if let Some(persisted) = read_persisted_locale() {
+        let normalized = normalize_locale(&persisted);
+        if catalogs().contains_key(&normalized) {
+            return normalized;
+        }
+    }
+
+    if let Some(os_locale) = sys_locale::get_locale() {
+        let normalized = normalize_locale(&os_locale);
+        if catalogs().contains_key(&normalized) {
+            return normalized;
+        }
+    }
+
+    DEFAULT_LOCALE.to_string()
+}
+
+/// Resolves `key` through the active locale's catalog, falling back to the default
+/// locale and then to the key itself.
+pub fn t(key: &str) -> String {
+    let locale = active_locale()
+        .read()
+        .map(|guard| guard.clone())
+        .unwrap_or_else(|_| DEFAULT_LOCALE.to_string());
+
+    catalogs()
+        .get(&locale)
+        .and_then(|messages| messages.get(key))
+        .or_else(|| catalogs().get(DEFAULT_LOCALE).and_then(|messages| messages.get(key)))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Resolves `key`, substituting `{name}`-style placeholders from `args`.
+pub fn t_with(key: &str, args: &[(&str, &str)]) -> String {
+    let mut message = t(key);
+    for (name, value) in args {
+        message = message.replace(&format!("{{{name}}}"), value);
+    }
+    message
+}
+
+/// Platform launch commands often only have a detail string (e.g. stderr) when the OS gave
+/// one; this substitutes a localized "unknown error" placeholder otherwise, so callers can
+/// always fold a `{detail}` arg into a single message template.
+pub fn detail_or_unknown(detail: &str) -> String {
+    if detail.trim().is_empty() {
+        t("error.detail_unknown")
+    } else {
+        detail.to_string()
+    }
+}
+
+/// Lists the locales bundled with the app, sorted for stable display order.
+pub fn get_available_locales() -> Vec<String> {
+    let mut locales: Vec<String> = catalogs().keys().cloned().collect();
+    locales.sort();
+    locales
+}
+
+/// Changes the active locale and persists the override, so it survives a restart.
+/// Called by the `set_locale` command, which also refreshes the tray menu's labels.
+pub fn apply_locale(locale: &str) -> Result<(), String> {
+    let normalized = normalize_locale(locale);
+    if !catalogs().contains_key(&normalized) {
+        return Err(t_with("error.unsupported_locale", &[("locale", locale)]));
+    }
+
+    *active_locale()
+        .write()
+        .map_err(|_| t("error.locale_state_poisoned"))? = normalized.clone();
+    write_persisted_locale(&normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_locale;
+
+    #[test]
+    fn normalizes_region_suffixed_locales() {
+        assert_eq!(normalize_locale("es-MX"), "es");
+        assert_eq!(normalize_locale("en_US"), "en");
+        assert_eq!(normalize_locale("fr"), "fr");
+    }
+}
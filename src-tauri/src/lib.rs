@@ -4,7 +4,6 @@ use std::env;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use tauri::{
     menu::{Menu, MenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
@@ -16,10 +15,58 @@ use tauri_plugin_autostart::ManagerExt;
 #[cfg(target_os = "macos")]
 use tauri_plugin_autostart::MacosLauncher;
 
+mod cci_watch;
+mod i18n;
+mod platform;
+mod process;
+
+use cci_watch::CciWatchState;
+use platform::{AppPlatform, CurrentPlatform};
+use process::ProcessIndex;
+
 const CONFIG_DIR_RELATIVE: &str = ".hymetalab/config";
+const LOG_DIR_RELATIVE: &str = ".hymetalab/logs";
 const APPS_FILE_NAME: &str = "apps.json";
 const TRAY_MENU_ID_OPEN_LAUNCHER: &str = "open_launcher";
 const TRAY_MENU_ID_QUIT: &str = "quit_launcher";
+const TRAY_ICON_ID: &str = "launcher-tray";
+
+/// Handles to the tray's menu items, kept so `set_locale` can relabel them in place
+/// instead of tearing down and rebuilding the tray.
+struct TrayHandles {
+    open_launcher_item: MenuItem<tauri::Wry>,
+    quit_item: MenuItem<tauri::Wry>,
+}
+
+#[tauri::command]
+fn get_available_locales() -> Vec<String> {
+    i18n::get_available_locales()
+}
+
+#[tauri::command]
+fn set_locale(app: AppHandle, locale: String) -> Result<(), String> {
+    i18n::apply_locale(&locale)?;
+    refresh_tray_labels(&app);
+    Ok(())
+}
+
+/// Relabels the tray's menu items and tooltip in the newly active locale.
+fn refresh_tray_labels(app: &AppHandle) {
+    if let Some(handles) = app.try_state::<TrayHandles>() {
+        if let Err(error) = handles.open_launcher_item.set_text(i18n::t("tray.open_launcher")) {
+            log::warn!("Failed to relabel tray item: {error}");
+        }
+        if let Err(error) = handles.quit_item.set_text(i18n::t("tray.quit")) {
+            log::warn!("Failed to relabel tray item: {error}");
+        }
+    }
+
+    if let Some(tray) = app.tray_by_id(TRAY_ICON_ID) {
+        if let Err(error) = tray.set_tooltip(Some(i18n::t("tray.tooltip"))) {
+            log::warn!("Failed to update tray tooltip: {error}");
+        }
+    }
+}
 
 #[tauri::command]
 fn launch_app(app_name: String) -> Result<(), String> {
@@ -27,30 +74,15 @@ fn launch_app(app_name: String) -> Result<(), String> {
         "companion" => "Companion",
         "dugout" => "Dugout",
         "hmm" => "HM Admin Console",
-        _ => return Err(format!("Unsupported app name: {app_name}")),
+        _ => return Err(i18n::t_with("error.unsupported_app_name", &[("name", &app_name)])),
     };
 
-    let output = Command::new("open")
-        .arg("-a")
-        .arg(mapped_name)
-        .output()
-        .map_err(|error| format!("Failed to execute open command: {error}"))?;
-
-    if output.status.success() {
-        Ok(())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        if stderr.is_empty() {
-            Err(format!(
-                "Failed to launch {mapped_name}. Expected app at /Applications/{mapped_name}.app"
-            ))
-        } else {
-            Err(format!("Failed to launch {mapped_name}: {stderr}"))
-        }
-    }
+    CurrentPlatform::launch_named_app(mapped_name).inspect_err(|error| {
+        log::warn!("Failed to launch {mapped_name}: {error}");
+    })
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 struct CciSignal {
     value: f64,
     timestamp: String,
@@ -130,72 +162,30 @@ fn bundle_name_from_app_path(path: &str) -> Option<String> {
         .map(std::borrow::ToOwned::to_owned)
 }
 
-fn is_launchable_app_process_line(command_line: &str, bundle_name: &str) -> bool {
-    let normalized_line = command_line.trim().to_ascii_lowercase();
-    if normalized_line.is_empty() {
-        return false;
-    }
-
-    let bundle_segment = format!("/{bundle_name}.app/contents/macos/");
-    if !normalized_line.contains(&bundle_segment.to_ascii_lowercase()) {
-        return false;
-    }
-
-    !normalized_line.contains("/backend-sidecar")
-}
-
-fn is_app_running_in_commands(bundle_name: &str, commands: &str) -> bool {
-    commands
-        .lines()
-        .any(|line| is_launchable_app_process_line(line, bundle_name))
-}
-
-fn read_process_commands_snapshot() -> String {
-    Command::new("ps")
-        .args(["-axo", "command"])
-        .output()
-        .ok()
-        .filter(|output| output.status.success())
-        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
-        .unwrap_or_default()
-}
-
 fn apps_registry_path() -> Result<PathBuf, String> {
-    let home_dir =
-        env::var("HOME").map_err(|error| format!("Could not resolve HOME: {error}"))?;
+    let home_dir = env::var("HOME")
+        .map_err(|error| i18n::t_with("error.resolve_home_failed", &[("detail", &error.to_string())]))?;
     Ok(Path::new(&home_dir)
         .join(CONFIG_DIR_RELATIVE)
         .join(APPS_FILE_NAME))
 }
 
-fn is_valid_app_bundle_path(path: &Path) -> bool {
-    let has_app_extension = path
-        .extension()
-        .and_then(|extension| extension.to_str())
-        .map(|extension| extension.eq_ignore_ascii_case("app"))
-        .unwrap_or(false);
-
-    has_app_extension && path.exists() && path.is_dir()
-}
-
 fn normalize_app_path(path: &str) -> Result<String, String> {
     let trimmed_path = path.trim();
     if trimmed_path.is_empty() {
-        return Err("App path is required.".to_string());
+        return Err(i18n::t("error.app_path_required"));
     }
 
     let as_path = Path::new(trimmed_path);
-    if !is_valid_app_bundle_path(as_path) {
-        return Err(format!(
-            "Invalid app bundle path: {trimmed_path}. Expected an existing .app directory."
-        ));
+    if !CurrentPlatform::is_valid_app_path(as_path) {
+        return Err(i18n::t_with("error.invalid_app_path", &[("path", trimmed_path)]));
     }
 
     let canonical = fs::canonicalize(as_path).unwrap_or_else(|_| as_path.to_path_buf());
     canonical
         .to_str()
         .map(std::borrow::ToOwned::to_owned)
-        .ok_or_else(|| "App path must be valid UTF-8.".to_string())
+        .ok_or_else(|| i18n::t("error.app_path_invalid_utf8"))
 }
 
 fn sort_and_dedupe_apps(apps: Vec<RegisteredApp>) -> Vec<RegisteredApp> {
@@ -229,10 +219,10 @@ fn read_registered_apps() -> Result<Vec<RegisteredApp>, String> {
     }
 
     let contents = fs::read_to_string(&registry_path)
-        .map_err(|error| format!("Failed to read app registry: {error}"))?;
+        .map_err(|error| i18n::t_with("error.read_registry_failed", &[("detail", &error.to_string())]))?;
 
     let apps = serde_json::from_str::<Vec<RegisteredApp>>(&contents)
-        .map_err(|error| format!("Failed to parse app registry: {error}"))?;
+        .map_err(|error| i18n::t_with("error.parse_registry_failed", &[("detail", &error.to_string())]))?;
 
     Ok(sort_and_dedupe_apps(apps))
 }
@@ -240,46 +230,19 @@ fn read_registered_apps() -> Result<Vec<RegisteredApp>, String> {
 fn write_registered_apps(apps: &[RegisteredApp]) -> Result<(), String> {
     let registry_path = apps_registry_path()?;
     if let Some(parent_dir) = registry_path.parent() {
-        fs::create_dir_all(parent_dir)
-            .map_err(|error| format!("Failed to create app registry directory: {error}"))?;
+        fs::create_dir_all(parent_dir).map_err(|error| {
+            i18n::t_with("error.create_registry_dir_failed", &[("detail", &error.to_string())])
+        })?;
     }
 
     let payload = serde_json::to_string_pretty(apps)
-        .map_err(|error| format!("Failed to serialize app registry: {error}"))?;
-
-    fs::write(&registry_path, payload)
-        .map_err(|error| format!("Failed to write app registry: {error}"))
-}
-
-fn collect_apps_from_directory(directory: &Path, apps: &mut Vec<RegisteredApp>) {
-    if !directory.exists() {
-        return;
-    }
-
-    let Ok(entries) = fs::read_dir(directory) else {
-        return;
-    };
+        .map_err(|error| i18n::t_with("error.serialize_registry_failed", &[("detail", &error.to_string())]))?;
 
-    for entry in entries.flatten() {
-        let candidate_path = entry.path();
-        if !is_valid_app_bundle_path(&candidate_path) {
-            continue;
-        }
-
-        let canonical_path = fs::canonicalize(&candidate_path).unwrap_or(candidate_path);
-        let Some(path_string) = canonical_path.to_str().map(std::borrow::ToOwned::to_owned) else {
-            continue;
-        };
-
-        let Some(bundle_name) = bundle_name_from_app_path(&path_string) else {
-            continue;
-        };
-
-        apps.push(RegisteredApp {
-            name: bundle_name,
-            path: path_string,
-        });
-    }
+    fs::write(&registry_path, payload).map_err(|error| {
+        let message = i18n::t_with("error.write_registry_failed", &[("detail", &error.to_string())]);
+        log::error!("{message}");
+        message
+    })
 }
 
 #[tauri::command]
@@ -291,14 +254,14 @@ fn get_registered_apps() -> Result<Vec<RegisteredApp>, String> {
 fn add_registered_app(path: String, name: Option<String>) -> Result<Vec<RegisteredApp>, String> {
     let normalized_path = normalize_app_path(&path)?;
     let fallback_name = bundle_name_from_app_path(&normalized_path)
-        .ok_or_else(|| "Failed to derive app name from path.".to_string())?;
+        .ok_or_else(|| i18n::t("error.derive_app_name_failed"))?;
     let normalized_name = name
         .unwrap_or(fallback_name)
         .trim()
         .to_string();
 
     if normalized_name.is_empty() {
-        return Err("App name cannot be empty.".to_string());
+        return Err(i18n::t("error.app_name_empty"));
     }
 
     let mut apps = read_registered_apps()?;
@@ -325,7 +288,7 @@ fn add_registered_app(path: String, name: Option<String>) -> Result<Vec<Register
 fn remove_registered_app(path: String) -> Result<Vec<RegisteredApp>, String> {
     let trimmed_path = path.trim();
     if trimmed_path.is_empty() {
-        return Err("App path is required.".to_string());
+        return Err(i18n::t("error.app_path_required"));
     }
 
     let apps = read_registered_apps()?;
@@ -342,46 +305,27 @@ fn remove_registered_app(path: String) -> Result<Vec<RegisteredApp>, String> {
 #[tauri::command]
 fn scan_installed_apps() -> Vec<RegisteredApp> {
     let mut discovered_apps: Vec<RegisteredApp> = Vec::new();
-    let home_dir = env::var("HOME").ok().map(PathBuf::from);
-
-    collect_apps_from_directory(Path::new("/Applications"), &mut discovered_apps);
-    if let Some(home_dir) = home_dir {
-        collect_apps_from_directory(&home_dir.join("Applications"), &mut discovered_apps);
-    }
-
+    CurrentPlatform::scan_installed_apps(&mut discovered_apps);
     sort_and_dedupe_apps(discovered_apps)
 }
 
 #[tauri::command]
 fn launch_registered_app(path: String) -> Result<(), String> {
     let normalized_path = normalize_app_path(&path)?;
-
-    let output = Command::new("open")
-        .arg(&normalized_path)
-        .output()
-        .map_err(|error| format!("Failed to execute open command: {error}"))?;
-
-    if output.status.success() {
-        Ok(())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        if stderr.is_empty() {
-            Err(format!("Failed to launch app at {normalized_path}"))
-        } else {
-            Err(format!("Failed to launch app at {normalized_path}: {stderr}"))
-        }
-    }
+    CurrentPlatform::launch_app_at_path(&normalized_path).inspect_err(|error| {
+        log::warn!("Failed to launch registered app at {normalized_path}: {error}");
+    })
 }
 
 #[tauri::command]
 fn get_running_registered_apps(paths: Vec<String>) -> Vec<RunningRegisteredApp> {
-    let commands = read_process_commands_snapshot();
+    let processes = ProcessIndex::capture();
 
     paths
         .into_iter()
         .map(|path| {
             let running = bundle_name_from_app_path(&path)
-                .map(|bundle_name| is_app_running_in_commands(&bundle_name, &commands))
+                .map(|bundle_name| processes.is_bundle_running(&bundle_name))
                 .unwrap_or(false);
 
             RunningRegisteredApp { path, running }
@@ -389,18 +333,47 @@ fn get_running_registered_apps(paths: Vec<String>) -> Vec<RunningRegisteredApp>
         .collect()
 }
 
+#[tauri::command]
+fn list_apps_for_file(file_path: String) -> Result<Vec<RegisteredApp>, String> {
+    // Registered apps are appended last so their user-chosen names win the "last write
+    // wins" dedupe in sort_and_dedupe_apps, instead of a freshly-scanned OS-default label
+    // overwriting an app the user renamed via add_registered_app.
+    let mut apps = Vec::new();
+    CurrentPlatform::scan_installed_apps(&mut apps);
+    apps.extend(read_registered_apps()?);
+    let apps = sort_and_dedupe_apps(apps);
+
+    let mut matching_apps = CurrentPlatform::apps_for_file(Path::new(&file_path), &apps);
+    matching_apps.sort_by(|left, right| left.name.to_ascii_lowercase().cmp(&right.name.to_ascii_lowercase()));
+    Ok(matching_apps)
+}
+
+#[tauri::command]
+fn open_with(file_path: String, app_path: String) -> Result<(), String> {
+    let normalized_app_path = normalize_app_path(&app_path)?;
+    CurrentPlatform::open_file_with(&normalized_app_path, &file_path)
+}
+
 fn show_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
-        let _ = window.show();
-        let _ = window.unminimize();
-        let _ = window.set_focus();
+        if let Err(error) = window.show() {
+            log::warn!("Failed to show main window: {error}");
+        }
+        if let Err(error) = window.unminimize() {
+            log::warn!("Failed to unminimize main window: {error}");
+        }
+        if let Err(error) = window.set_focus() {
+            log::warn!("Failed to focus main window: {error}");
+        }
     }
 }
 
 fn toggle_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         if window.is_visible().unwrap_or(false) {
-            let _ = window.hide();
+            if let Err(error) = window.hide() {
+                log::warn!("Failed to hide main window: {error}");
+            }
         } else {
             show_main_window(app);
         }
@@ -411,22 +384,27 @@ fn setup_tray(app: &AppHandle) -> tauri::Result<()> {
     let open_launcher_item = MenuItem::with_id(
         app,
         TRAY_MENU_ID_OPEN_LAUNCHER,
-        "Open Launcher",
+        i18n::t("tray.open_launcher"),
         true,
         None::<&str>,
     )?;
-    let quit_item = MenuItem::with_id(app, TRAY_MENU_ID_QUIT, "Quit", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, TRAY_MENU_ID_QUIT, i18n::t("tray.quit"), true, None::<&str>)?;
     let tray_menu = Menu::with_items(app, &[&open_launcher_item, &quit_item])?;
 
+    app.manage(TrayHandles {
+        open_launcher_item: open_launcher_item.clone(),
+        quit_item: quit_item.clone(),
+    });
+
     let tray_icon = app
         .default_window_icon()
         .cloned()
         .expect("default window icon should be available");
 
-    TrayIconBuilder::with_id("launcher-tray")
+    TrayIconBuilder::with_id(TRAY_ICON_ID)
         .icon(tray_icon)
         .menu(&tray_menu)
-        .tooltip("HYMetaLab Launcher")
+        .tooltip(i18n::t("tray.tooltip"))
         .show_menu_on_left_click(false)
         .on_menu_event(|app, event| match event.id().as_ref() {
             TRAY_MENU_ID_OPEN_LAUNCHER => {
@@ -452,11 +430,12 @@ fn setup_tray(app: &AppHandle) -> tauri::Result<()> {
 
 #[tauri::command]
 fn read_cci_signals() -> Result<CciSignalsResponse, String> {
-    let home_dir = env::var("HOME").map_err(|error| format!("Could not resolve HOME: {error}"))?;
+    let home_dir = env::var("HOME")
+        .map_err(|error| i18n::t_with("error.resolve_home_failed", &[("detail", &error.to_string())]))?;
     let bus_dir = Path::new(&home_dir).join(".hymetalab/shared/cci-bus");
 
     fs::create_dir_all(&bus_dir)
-        .map_err(|error| format!("Failed to create cci-bus directory: {error}"))?;
+        .map_err(|error| i18n::t_with("error.create_cci_bus_dir_failed", &[("detail", &error.to_string())]))?;
 
     let companion = read_signal_file(&bus_dir.join("companion-signals.jsonl"));
     let dugout = read_signal_file(&bus_dir.join("dugout-signals.jsonl"));
@@ -471,15 +450,45 @@ fn read_cci_signals() -> Result<CciSignalsResponse, String> {
 
 #[tauri::command]
 fn get_running_apps() -> RunningAppsResponse {
-    let commands = read_process_commands_snapshot();
+    let processes = ProcessIndex::capture();
 
     RunningAppsResponse {
-        companion: is_app_running_in_commands("Companion", &commands),
-        dugout: is_app_running_in_commands("Dugout", &commands),
-        hmm: is_app_running_in_commands("HM Admin Console", &commands),
+        companion: processes.is_bundle_running("Companion"),
+        dugout: processes.is_bundle_running("Dugout"),
+        hmm: processes.is_bundle_running("HM Admin Console"),
     }
 }
 
+/// Resolves the directory rotating log files are written to (`~/.hymetalab/logs`),
+/// falling back to the current directory if `HOME` can't be resolved so logging never
+/// blocks startup.
+fn log_dir() -> PathBuf {
+    env::var("HOME")
+        .map(|home_dir| Path::new(&home_dir).join(LOG_DIR_RELATIVE))
+        .unwrap_or_else(|_| PathBuf::from(LOG_DIR_RELATIVE))
+}
+
+/// Builds the logging plugin: stderr plus a rotating log file under `~/.hymetalab/logs`,
+/// at a level configurable via `HYMETALAB_LOG_LEVEL` (defaults to `info`).
+fn build_log_plugin() -> tauri_plugin_log::TauriPlugin<tauri::Wry> {
+    let log_level = env::var("HYMETALAB_LOG_LEVEL")
+        .ok()
+        .and_then(|level| level.parse::<log::LevelFilter>().ok())
+        .unwrap_or(log::LevelFilter::Info);
+
+    tauri_plugin_log::Builder::new()
+        .level(log_level)
+        .targets([
+            tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Stderr),
+            tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Folder {
+                path: log_dir(),
+                file_name: None,
+            }),
+        ])
+        .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepAll)
+        .build()
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -487,17 +496,24 @@ pub fn run() {
             #[cfg(target_os = "macos")]
             app.set_activation_policy(ActivationPolicy::Accessory);
 
-            setup_tray(&app.handle())?;
+            if let Err(error) = setup_tray(&app.handle()) {
+                log::error!("Failed to set up tray icon: {error}");
+                return Err(error);
+            }
 
             let auto_launch = app.autolaunch();
             if !auto_launch.is_enabled().unwrap_or(false) {
-                let _ = auto_launch.enable();
+                if let Err(error) = auto_launch.enable() {
+                    log::warn!("Failed to enable autostart: {error}");
+                }
             }
 
             let launched_from_autostart = env::args().any(|arg| arg == "--autostart");
             if launched_from_autostart {
                 if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.hide();
+                    if let Err(error) = window.hide() {
+                        log::warn!("Failed to hide main window on autostart launch: {error}");
+                    }
                 }
             }
 
@@ -509,9 +525,12 @@ pub fn run() {
             }
 
             if let WindowEvent::Focused(false) = event {
-                let _ = window.hide();
+                if let Err(error) = window.hide() {
+                    log::warn!("Failed to hide main window on focus loss: {error}");
+                }
             }
         })
+        .plugin(build_log_plugin())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_opener::init())
@@ -523,6 +542,7 @@ pub fn run() {
 
             builder.build()
         })
+        .manage(CciWatchState::default())
         .invoke_handler(tauri::generate_handler![
             launch_app,
             read_cci_signals,
@@ -532,7 +552,13 @@ pub fn run() {
             remove_registered_app,
             scan_installed_apps,
             launch_registered_app,
-            get_running_registered_apps
+            get_running_registered_apps,
+            list_apps_for_file,
+            open_with,
+            cci_watch::start_cci_watch,
+            cci_watch::stop_cci_watch,
+            get_available_locales,
+            set_locale
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -540,53 +566,7 @@ pub fn run() {
 
 #[cfg(test)]
 mod tests {
-    use super::{
-        bundle_name_from_app_path,
-        is_app_running_in_commands,
-        is_launchable_app_process_line,
-        sort_and_dedupe_apps,
-        RegisteredApp,
-    };
-
-    #[test]
-    fn detects_app_process_from_any_location_case_insensitive() {
-        let line = "/Users/jordanheckler/companion/src-tauri/target/release/bundle/macos/companion.app/Contents/MacOS/app";
-        assert!(is_launchable_app_process_line(line, "Companion"));
-    }
-
-    #[test]
-    fn ignores_sidecar_processes() {
-        let line = "/Applications/Dugout.app/Contents/MacOS/backend-sidecar";
-        assert!(!is_launchable_app_process_line(line, "Dugout"));
-    }
-
-    #[test]
-    fn ignores_sidecar_processes_with_arguments() {
-        let line = "/Applications/Dugout.app/Contents/MacOS/backend-sidecar --port 7001";
-        assert!(!is_launchable_app_process_line(line, "Dugout"));
-    }
-
-    #[test]
-    fn does_not_cross_match_other_bundles() {
-        let line = "/Applications/Dugout.app/Contents/MacOS/app";
-        assert!(!is_launchable_app_process_line(line, "Companion"));
-    }
-
-    #[test]
-    fn running_state_uses_matching_non_sidecar_processes_only() {
-        let commands = "\
-/Applications/Dugout.app/Contents/MacOS/backend-sidecar
-/Users/jordanheckler/companion/src-tauri/target/release/bundle/macos/companion.app/Contents/MacOS/app
-";
-        assert!(is_app_running_in_commands("Companion", commands));
-        assert!(!is_app_running_in_commands("Dugout", commands));
-    }
-
-    #[test]
-    fn supports_bundle_names_with_spaces() {
-        let commands = "/Applications/HM Admin Console.app/Contents/MacOS/app";
-        assert!(is_app_running_in_commands("HM Admin Console", commands));
-    }
+    use super::{bundle_name_from_app_path, sort_and_dedupe_apps, RegisteredApp};
 
     #[test]
     fn derives_bundle_name_from_path() {
@@ -0,0 +1,433 @@
+//! Linux application discovery and launching.
+//!
+//! Installed applications are discovered by parsing the `.desktop` entries XDG requires
+//! every well-behaved application to install, rather than assuming a single fixed install
+//! location. Launching shells out to the resolved `Exec=` command line directly, since
+//! there's no Linux equivalent of macOS's `open -a`.
+
+use super::sandbox::{is_running_in_appimage, is_running_in_flatpak, is_running_in_snap};
+use super::AppPlatform;
+use crate::i18n;
+use crate::RegisteredApp;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// How many leading bytes of a file to read when sniffing its MIME type by magic number —
+/// enough for every prefix in `sniff_mime_type`, without buffering arbitrarily large files.
+const MIME_SNIFF_BYTE_COUNT: u64 = 16;
+
+/// Env vars that carry a launcher's own bundled GStreamer/GTK plugin search paths, which
+/// must not leak into apps spawned from inside an AppImage/Flatpak/Snap packaging.
+const GSTREAMER_GTK_PLUGIN_VARS: &[&str] = &[
+    "GST_PLUGIN_PATH",
+    "GST_PLUGIN_SYSTEM_PATH",
+    "GST_PLUGIN_SCANNER",
+    "GTK_PATH",
+    "GTK_EXE_PREFIX",
+    "GTK_DATA_PREFIX",
+];
+
+/// Env vars that hold `:`-separated path lists and should be de-duplicated rather than
+/// passed through verbatim when building a child's environment.
+const PATH_STYLE_VARS: &[&str] = &["PATH", "XDG_DATA_DIRS", "XDG_CONFIG_DIRS", "LD_LIBRARY_PATH"];
+
+pub struct LinuxPlatform;
+
+impl AppPlatform for LinuxPlatform {
+    fn is_valid_app_path(path: &Path) -> bool {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.eq_ignore_ascii_case("desktop"))
+            .unwrap_or(false)
+            && path.is_file()
+    }
+
+    fn scan_installed_apps(apps: &mut Vec<RegisteredApp>) {
+        for directory in xdg_application_dirs() {
+            collect_desktop_entries(&directory, apps);
+        }
+    }
+
+    fn launch_app_at_path(path: &str) -> Result<(), String> {
+        let entry = parse_desktop_entry(Path::new(path))
+            .ok_or_else(|| i18n::t_with("error.parse_desktop_entry_failed", &[("path", path)]))?;
+        spawn_exec_line(&entry.exec, None)
+    }
+
+    fn launch_named_app(display_name: &str) -> Result<(), String> {
+        let mut apps = Vec::new();
+        Self::scan_installed_apps(&mut apps);
+        let matched_app = apps
+            .into_iter()
+            .find(|app| app.name.eq_ignore_ascii_case(display_name))
+            .ok_or_else(|| i18n::t_with("error.no_app_matches", &[("name", display_name)]))?;
+        Self::launch_app_at_path(&matched_app.path)
+    }
+
+    fn apps_for_file(file_path: &Path, candidates: &[RegisteredApp]) -> Vec<RegisteredApp> {
+        let mime_type = detect_mime_type(file_path);
+
+        candidates
+            .iter()
+            .filter(|app| {
+                parse_desktop_entry(Path::new(&app.path))
+                    .is_some_and(|entry| entry.mime_types.iter().any(|supported| mime_type_matches(supported, &mime_type)))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn open_file_with(app_path: &str, file_path: &str) -> Result<(), String> {
+        let entry = parse_desktop_entry(Path::new(app_path))
+            .ok_or_else(|| i18n::t_with("error.parse_desktop_entry_failed", &[("path", app_path)]))?;
+        spawn_exec_line(&entry.exec, Some(file_path))
+    }
+
+    fn process_matches_bundle(executable_path: &str, bundle_name: &str) -> bool {
+        let normalized_path = executable_path.trim().to_ascii_lowercase();
+        if normalized_path.is_empty() || normalized_path.contains("/backend-sidecar") {
+            return false;
+        }
+
+        Path::new(&normalized_path)
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .is_some_and(|file_name| file_name == bundle_name.to_ascii_lowercase())
+    }
+}
+
+/// A parsed subset of a `.desktop` file's `[Desktop Entry]` group.
+struct DesktopEntry {
+    name: String,
+    exec: String,
+    #[allow(dead_code)]
+    icon: Option<String>,
+    mime_types: Vec<String>,
+}
+
+/// Common extensions mapped to their MIME type, checked before falling back to content
+/// sniffing.
+const EXTENSION_MIME_TYPES: &[(&str, &str)] = &[
+    ("txt", "text/plain"),
+    ("md", "text/markdown"),
+    ("json", "application/json"),
+    ("jsonl", "application/json"),
+    ("csv", "text/csv"),
+    ("html", "text/html"),
+    ("htm", "text/html"),
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("svg", "image/svg+xml"),
+    ("mp4", "video/mp4"),
+    ("mp3", "audio/mpeg"),
+    ("zip", "application/zip"),
+];
+
+/// Detects a file's MIME type by extension first, falling back to sniffing the first few
+/// bytes for well-known magic numbers, and finally `application/octet-stream`.
+fn detect_mime_type(file_path: &Path) -> String {
+    if let Some(extension) = file_path.extension().and_then(|extension| extension.to_str()) {
+        if let Some((_, mime_type)) = EXTENSION_MIME_TYPES
+            .iter()
+            .find(|(known_extension, _)| known_extension.eq_ignore_ascii_case(extension))
+        {
+            return (*mime_type).to_string();
+        }
+    }
+
+    sniff_mime_type(file_path).unwrap_or_else(|| "application/octet-stream".to_string())
+}
+
+fn sniff_mime_type(file_path: &Path) -> Option<String> {
+    let mut header = Vec::new();
+    fs::File::open(file_path)
+        .ok()?
+        .take(MIME_SNIFF_BYTE_COUNT)
+        .read_to_end(&mut header)
+        .ok()?;
+
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png".to_string())
+    } else if header.starts_with(b"\xff\xd8\xff") {
+        Some("image/jpeg".to_string())
+    } else if header.starts_with(b"%PDF-") {
+        Some("application/pdf".to_string())
+    } else if header.starts_with(b"PK\x03\x04") {
+        Some("application/zip".to_string())
+    } else {
+        None
+    }
+}
+
+/// Matches a `.desktop` entry's `MimeType=` value (which may end in a `/*` wildcard
+/// subtype) against a detected MIME type.
+fn mime_type_matches(supported: &str, detected: &str) -> bool {
+    match supported.strip_suffix("/*") {
+        Some(supported_type) => detected
+            .split_once('/')
+            .map(|(detected_type, _)| detected_type.eq_ignore_ascii_case(supported_type))
+            .unwrap_or(false),
+        None => supported.eq_ignore_ascii_case(detected),
+    }
+}
+
+/// The XDG application directories to scan, in priority order: the user's own
+/// `~/.local/share/applications` followed by each `applications` subdirectory of
+/// `$XDG_DATA_DIRS`.
+fn xdg_application_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(home_dir) = env::var_os("HOME") {
+        dirs.push(Path::new(&home_dir).join(".local/share/applications"));
+    }
+
+    let data_dirs =
+        env::var("XDG_DATA_DIRS").unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+    for data_dir in data_dirs.split(':').filter(|entry| !entry.is_empty()) {
+        dirs.push(Path::new(data_dir).join("applications"));
+    }
+
+    dirs
+}
+
+fn collect_desktop_entries(directory: &Path, apps: &mut Vec<RegisteredApp>) {
+    if !directory.exists() {
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !LinuxPlatform::is_valid_app_path(&path) {
+            continue;
+        }
+
+        let Some(desktop_entry) = parse_desktop_entry(&path) else {
+            continue;
+        };
+        let Some(path_string) = path.to_str().map(std::borrow::ToOwned::to_owned) else {
+            continue;
+        };
+
+        apps.push(RegisteredApp {
+            name: desktop_entry.name,
+            path: path_string,
+        });
+    }
+}
+
+/// Parses the `[Desktop Entry]` group of a `.desktop` file, extracting `Name`, `Exec`,
+/// `Icon`, and `MimeType`. `Exec` has its `%f`/`%u`/`%F`/`%U` field codes stripped, since
+/// this launcher spawns the command directly rather than through a shell that would
+/// substitute them.
+fn parse_desktop_entry(path: &Path) -> Option<DesktopEntry> {
+    let contents = fs::read_to_string(path).ok()?;
+    let mut fields: HashMap<&str, String> = HashMap::new();
+    let mut in_desktop_entry_group = false;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_desktop_entry_group = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_desktop_entry_group || line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            fields.insert(key.trim(), value.trim().to_string());
+        }
+    }
+
+    let name = fields.get("Name")?.clone();
+    let exec = strip_exec_field_codes(fields.get("Exec")?);
+    let icon = fields.get("Icon").cloned();
+    let mime_types = fields
+        .get("MimeType")
+        .map(|value| {
+            value
+                .split(';')
+                .filter(|mime_type| !mime_type.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(DesktopEntry {
+        name,
+        exec,
+        icon,
+        mime_types,
+    })
+}
+
+/// Strips the `%f`, `%u`, `%F`, `%U` (and other `%x`) field codes the XDG desktop entry
+/// spec permits in `Exec=`, collapsing any resulting double spaces.
+fn strip_exec_field_codes(exec: &str) -> String {
+    let mut stripped = String::with_capacity(exec.len());
+    let mut chars = exec.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch == '%' {
+            match chars.peek() {
+                Some('%') => {
+                    stripped.push('%');
+                    chars.next();
+                }
+                Some(_) => {
+                    chars.next();
+                }
+                None => stripped.push('%'),
+            }
+            continue;
+        }
+        stripped.push(ch);
+    }
+
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Spawns an already field-code-stripped `Exec=` line, optionally appending `extra_arg`
+/// (a file path to open) as a single argument. `extra_arg` is passed straight to
+/// `Command::arg` rather than being concatenated into `exec` and re-split, so paths
+/// containing spaces aren't chopped into multiple bogus argv entries.
+fn spawn_exec_line(exec: &str, extra_arg: Option<&str>) -> Result<(), String> {
+    let mut parts = exec.split_whitespace();
+    let program = parts.next().ok_or_else(|| i18n::t("error.empty_exec_line"))?;
+
+    let mut command = Command::new(program);
+    command.args(parts);
+    if let Some(arg) = extra_arg {
+        command.arg(arg);
+    }
+    apply_sandboxed_child_env(&mut command);
+
+    command.spawn().map(|_| ()).map_err(|error| {
+        i18n::t_with("error.launch_exec_failed", &[("program", program), ("detail", &error.to_string())])
+    })
+}
+
+/// When this launcher is itself running inside a Flatpak, Snap, or AppImage, rebuilds the
+/// child's environment from [`normalized_env`] instead of inheriting the launcher's raw
+/// environment, so the launcher's own bundled library paths don't leak into the app.
+fn apply_sandboxed_child_env(command: &mut Command) {
+    if !(is_running_in_flatpak() || is_running_in_snap() || is_running_in_appimage()) {
+        return;
+    }
+
+    command.env_clear();
+    for (key, value) in normalized_env(env::vars()) {
+        command.env(key, value);
+    }
+}
+
+/// De-duplicates `PATH`-style lists (keeping the later, lower-priority entry on
+/// collision), drops empty vars entirely rather than passing them through as `""`, and
+/// strips GStreamer/GTK plugin-path overrides that belong to the launcher's own bundled
+/// runtime.
+fn normalized_env(vars: impl Iterator<Item = (String, String)>) -> Vec<(String, String)> {
+    vars.filter(|(key, value)| !value.is_empty() && !GSTREAMER_GTK_PLUGIN_VARS.contains(&key.as_str()))
+        .map(|(key, value)| {
+            if PATH_STYLE_VARS.contains(&key.as_str()) {
+                (key, dedupe_path_list(&value))
+            } else {
+                (key, value)
+            }
+        })
+        .collect()
+}
+
+/// De-duplicates a `:`-separated path list, keeping the *last* occurrence of each entry, so
+/// an entry that also appears earlier (at higher lookup priority) in the list keeps only its
+/// later, lower-priority position.
+fn dedupe_path_list(list: &str) -> String {
+    let mut seen = HashSet::new();
+    let mut deduped: Vec<&str> = Vec::new();
+
+    for entry in list.split(':').rev().filter(|entry| !entry.is_empty()) {
+        if seen.insert(entry) {
+            deduped.push(entry);
+        }
+    }
+
+    deduped.reverse();
+    deduped.join(":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_field_codes_from_exec() {
+        assert_eq!(strip_exec_field_codes("companion %U --flag"), "companion --flag");
+        assert_eq!(strip_exec_field_codes("companion %%f literal"), "companion %f literal");
+    }
+
+    #[test]
+    fn dedupes_path_list_keeping_later_entry() {
+        assert_eq!(dedupe_path_list("/a:/b:/a"), "/b:/a");
+    }
+
+    #[test]
+    fn normalized_env_drops_empty_vars_and_plugin_overrides() {
+        let vars = vec![
+            ("PATH".to_string(), "/a:/b:/a".to_string()),
+            ("GST_PLUGIN_PATH".to_string(), "/bundled/gst".to_string()),
+            ("SOME_EMPTY_VAR".to_string(), String::new()),
+            ("KEPT_VAR".to_string(), "value".to_string()),
+        ];
+
+        let normalized = normalized_env(vars.into_iter());
+        assert_eq!(
+            normalized,
+            vec![
+                ("PATH".to_string(), "/b:/a".to_string()),
+                ("KEPT_VAR".to_string(), "value".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_mime_type_from_extension() {
+        assert_eq!(detect_mime_type(Path::new("notes.md")), "text/markdown");
+        assert_eq!(detect_mime_type(Path::new("archive.zip")), "application/zip");
+    }
+
+    #[test]
+    fn mime_type_matches_supports_wildcard_subtype() {
+        assert!(mime_type_matches("image/*", "image/png"));
+        assert!(mime_type_matches("text/plain", "text/plain"));
+        assert!(!mime_type_matches("image/*", "text/plain"));
+    }
+
+    #[test]
+    fn parses_desktop_entry_fields() {
+        let dir = env::temp_dir().join(format!("hymetalab-desktop-entry-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("companion.desktop");
+        fs::write(
+            &path,
+            "[Desktop Entry]\nName=Companion\nExec=companion %U\nIcon=companion\nMimeType=text/plain;\n",
+        )
+        .unwrap();
+
+        let entry = parse_desktop_entry(&path).unwrap();
+        assert_eq!(entry.name, "Companion");
+        assert_eq!(entry.exec, "companion");
+        assert_eq!(entry.icon.as_deref(), Some("companion"));
+        assert_eq!(entry.mime_types, vec!["text/plain".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
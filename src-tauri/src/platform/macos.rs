@@ -0,0 +1,210 @@
+//! macOS application discovery and launching: apps are `.app` bundles, discovered under
+//! `/Applications` and `~/Applications`, and launched via `open`.
+
+use super::AppPlatform;
+use crate::i18n;
+use crate::{bundle_name_from_app_path, RegisteredApp};
+use core_foundation::array::{CFArray, CFArrayRef};
+use core_foundation::base::TCFType;
+use core_foundation::url::{CFURL, CFURLRef};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[allow(non_upper_case_globals)]
+const kLSRolesAll: u32 = 0xFFFF_FFFF;
+
+#[link(name = "CoreServices", kind = "framework")]
+extern "C" {
+    fn LSCopyApplicationURLsForURL(in_url: CFURLRef, in_role_mask: u32) -> CFArrayRef;
+}
+
+pub struct MacosPlatform;
+
+impl AppPlatform for MacosPlatform {
+    fn is_valid_app_path(path: &Path) -> bool {
+        let has_app_extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.eq_ignore_ascii_case("app"))
+            .unwrap_or(false);
+
+        has_app_extension && path.exists() && path.is_dir()
+    }
+
+    fn scan_installed_apps(apps: &mut Vec<RegisteredApp>) {
+        collect_apps_from_directory(Path::new("/Applications"), apps);
+        if let Some(home_dir) = env::var_os("HOME").map(PathBuf::from) {
+            collect_apps_from_directory(&home_dir.join("Applications"), apps);
+        }
+    }
+
+    fn launch_app_at_path(path: &str) -> Result<(), String> {
+        let output = Command::new("open")
+            .arg(path)
+            .output()
+            .map_err(|error| i18n::t_with("error.open_command_failed", &[("detail", &error.to_string())]))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            Err(i18n::t_with(
+                "error.launch_app_path_failed",
+                &[("path", path), ("detail", &i18n::detail_or_unknown(&stderr))],
+            ))
+        }
+    }
+
+    fn launch_named_app(display_name: &str) -> Result<(), String> {
+        let output = Command::new("open")
+            .arg("-a")
+            .arg(display_name)
+            .output()
+            .map_err(|error| i18n::t_with("error.open_command_failed", &[("detail", &error.to_string())]))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            if stderr.is_empty() {
+                Err(i18n::t_with("error.launch_named_app_not_found", &[("name", display_name)]))
+            } else {
+                Err(i18n::t_with(
+                    "error.launch_named_app_failed",
+                    &[("name", display_name), ("detail", &stderr)],
+                ))
+            }
+        }
+    }
+
+    fn apps_for_file(file_path: &Path, candidates: &[RegisteredApp]) -> Vec<RegisteredApp> {
+        let handler_bundle_paths = application_bundle_paths_for_file(file_path);
+
+        candidates
+            .iter()
+            .filter(|app| {
+                handler_bundle_paths
+                    .iter()
+                    .any(|handler_path| app.path.eq_ignore_ascii_case(handler_path))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn open_file_with(app_path: &str, file_path: &str) -> Result<(), String> {
+        let output = Command::new("open")
+            .arg("-a")
+            .arg(app_path)
+            .arg(file_path)
+            .output()
+            .map_err(|error| i18n::t_with("error.open_command_failed", &[("detail", &error.to_string())]))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            Err(i18n::t_with(
+                "error.open_with_failed",
+                &[("file", file_path), ("app", app_path), ("detail", &i18n::detail_or_unknown(&stderr))],
+            ))
+        }
+    }
+
+    fn process_matches_bundle(executable_path: &str, bundle_name: &str) -> bool {
+        let normalized_path = executable_path.trim().to_ascii_lowercase();
+        if normalized_path.is_empty() {
+            return false;
+        }
+
+        let bundle_segment = format!("/{bundle_name}.app/contents/macos/");
+        if !normalized_path.contains(&bundle_segment.to_ascii_lowercase()) {
+            return false;
+        }
+
+        !normalized_path.contains("/backend-sidecar")
+    }
+}
+
+/// Asks LaunchServices for every application bundle capable of opening `file_path` via
+/// `LSCopyApplicationURLsForURL`, the same registry Finder's own "Open With" menu reads
+/// from, rather than relying on an optional CLI that most end users don't have installed.
+fn application_bundle_paths_for_file(file_path: &Path) -> Vec<String> {
+    let Some(file_url) = CFURL::from_path(file_path, false) else {
+        return Vec::new();
+    };
+
+    let array_ref = unsafe { LSCopyApplicationURLsForURL(file_url.as_concrete_TypeRef(), kLSRolesAll) };
+    if array_ref.is_null() {
+        return Vec::new();
+    }
+
+    let handler_urls: CFArray<CFURL> = unsafe { CFArray::wrap_under_create_rule(array_ref) };
+    handler_urls
+        .iter()
+        .filter_map(|url| url.to_path())
+        .filter_map(|path| path.to_str().map(str::to_string))
+        .collect()
+}
+
+fn collect_apps_from_directory(directory: &Path, apps: &mut Vec<RegisteredApp>) {
+    if !directory.exists() {
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let candidate_path = entry.path();
+        if !MacosPlatform::is_valid_app_path(&candidate_path) {
+            continue;
+        }
+
+        let canonical_path = fs::canonicalize(&candidate_path).unwrap_or(candidate_path);
+        let Some(path_string) = canonical_path.to_str().map(std::borrow::ToOwned::to_owned) else {
+            continue;
+        };
+
+        let Some(bundle_name) = bundle_name_from_app_path(&path_string) else {
+            continue;
+        };
+
+        apps.push(RegisteredApp {
+            name: bundle_name,
+            path: path_string,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MacosPlatform;
+    use crate::platform::AppPlatform;
+
+    #[test]
+    fn detects_app_process_from_any_location_case_insensitive() {
+        let path = "/Users/jordanheckler/companion/src-tauri/target/release/bundle/macos/companion.app/Contents/MacOS/app";
+        assert!(MacosPlatform::process_matches_bundle(path, "Companion"));
+    }
+
+    #[test]
+    fn ignores_sidecar_processes() {
+        let path = "/Applications/Dugout.app/Contents/MacOS/backend-sidecar";
+        assert!(!MacosPlatform::process_matches_bundle(path, "Dugout"));
+    }
+
+    #[test]
+    fn does_not_cross_match_other_bundles() {
+        let path = "/Applications/Dugout.app/Contents/MacOS/app";
+        assert!(!MacosPlatform::process_matches_bundle(path, "Companion"));
+    }
+
+    #[test]
+    fn supports_bundle_names_with_spaces() {
+        let path = "/Applications/HM Admin Console.app/Contents/MacOS/app";
+        assert!(MacosPlatform::process_matches_bundle(path, "HM Admin Console"));
+    }
+}
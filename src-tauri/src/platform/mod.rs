@@ -0,0 +1,56 @@
+//! Platform-specific application discovery and launching.
+//!
+//! `launch_app`, `launch_registered_app`, `scan_installed_apps`, and `normalize_app_path`
+//! in `lib.rs` delegate to [`CurrentPlatform`], which resolves at compile time to the
+//! implementation for the target OS. Each implementation knows how to discover installed
+//! applications, validate that a path actually points at one, and launch it.
+
+mod sandbox;
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "windows")]
+mod windows;
+
+pub use sandbox::{is_running_in_appimage, is_running_in_flatpak, is_running_in_snap};
+
+#[cfg(target_os = "macos")]
+pub use macos::MacosPlatform as CurrentPlatform;
+#[cfg(target_os = "linux")]
+pub use linux::LinuxPlatform as CurrentPlatform;
+#[cfg(target_os = "windows")]
+pub use windows::WindowsPlatform as CurrentPlatform;
+
+use crate::RegisteredApp;
+use std::path::Path;
+
+/// Discovers and launches applications in a way appropriate for the current OS.
+pub trait AppPlatform {
+    /// Returns `true` if `path` points at something this platform considers a launchable
+    /// application entry (a `.app` bundle, a `.desktop` file, a `.lnk`/`.exe`, ...).
+    fn is_valid_app_path(path: &Path) -> bool;
+
+    /// Scans the platform's standard application directories and appends any apps found
+    /// to `apps`.
+    fn scan_installed_apps(apps: &mut Vec<RegisteredApp>);
+
+    /// Launches the application at `path`.
+    fn launch_app_at_path(path: &str) -> Result<(), String>;
+
+    /// Launches a known app by its display name (e.g. "Companion"), as used by the
+    /// `launch_app` shortcut command.
+    fn launch_named_app(display_name: &str) -> Result<(), String>;
+
+    /// Filters `candidates` down to the apps capable of opening `file_path`.
+    fn apps_for_file(file_path: &Path, candidates: &[RegisteredApp]) -> Vec<RegisteredApp>;
+
+    /// Opens `file_path` with the app at `app_path`.
+    fn open_file_with(app_path: &str, file_path: &str) -> Result<(), String>;
+
+    /// Returns `true` if `executable_path` (a running process's resolved executable path)
+    /// is the launchable process for the app named `bundle_name`, excluding known helper
+    /// processes (e.g. the backend sidecar).
+    fn process_matches_bundle(executable_path: &str, bundle_name: &str) -> bool;
+}
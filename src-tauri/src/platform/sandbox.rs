@@ -0,0 +1,21 @@
+//! Detects whether this launcher binary itself is running inside a Flatpak, Snap, or
+//! AppImage container. Knowing this matters because a sandboxed/packaged launcher must
+//! not leak its own bundled library paths into the apps it spawns.
+
+use std::env;
+use std::path::Path;
+
+/// `true` when running inside a Flatpak sandbox.
+pub fn is_running_in_flatpak() -> bool {
+    Path::new("/.flatpak-info").exists() || env::var_os("FLATPAK_ID").is_some()
+}
+
+/// `true` when running inside a Snap.
+pub fn is_running_in_snap() -> bool {
+    env::var_os("SNAP").is_some()
+}
+
+/// `true` when running as an AppImage (mounted and executed via the AppImage runtime).
+pub fn is_running_in_appimage() -> bool {
+    env::var_os("APPIMAGE").is_some()
+}
@@ -0,0 +1,252 @@
+//! Windows application discovery and launching.
+//!
+//! Installed applications are discovered as Start Menu `.lnk` shortcuts rather than a
+//! fixed install directory, and launched via `ShellExecute`, which (unlike spawning an
+//! `.exe` path directly) correctly resolves shortcuts, verbs, and file associations.
+
+use super::AppPlatform;
+use crate::i18n;
+use crate::RegisteredApp;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::os::windows::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::System::Registry::{
+    RegGetValueW, HKEY, HKEY_CLASSES_ROOT, HKEY_CURRENT_USER, RRF_RT_REG_SZ,
+};
+use windows::Win32::UI::Shell::ShellExecuteW;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+pub struct WindowsPlatform;
+
+impl AppPlatform for WindowsPlatform {
+    fn is_valid_app_path(path: &Path) -> bool {
+        (has_extension(path, "lnk") || has_extension(path, "exe")) && path.is_file()
+    }
+
+    fn scan_installed_apps(apps: &mut Vec<RegisteredApp>) {
+        for directory in start_menu_dirs() {
+            collect_shortcuts(&directory, apps);
+        }
+    }
+
+    fn launch_app_at_path(path: &str) -> Result<(), String> {
+        shell_execute_open(path)
+    }
+
+    fn launch_named_app(display_name: &str) -> Result<(), String> {
+        let mut apps = Vec::new();
+        Self::scan_installed_apps(&mut apps);
+        let matched_app = apps
+            .into_iter()
+            .find(|app| app.name.eq_ignore_ascii_case(display_name))
+            .ok_or_else(|| i18n::t_with("error.no_app_matches", &[("name", display_name)]))?;
+        Self::launch_app_at_path(&matched_app.path)
+    }
+
+    fn apps_for_file(file_path: &Path, candidates: &[RegisteredApp]) -> Vec<RegisteredApp> {
+        let Some(handler_exe) = registered_handler_exe(file_path) else {
+            return Vec::new();
+        };
+
+        candidates
+            .iter()
+            .filter(|app| {
+                Path::new(&app.path)
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|stem| handler_exe.to_ascii_lowercase().contains(&stem.to_ascii_lowercase()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn open_file_with(app_path: &str, file_path: &str) -> Result<(), String> {
+        let wide_file_path = to_wide(file_path);
+        let wide_app_path = to_wide(app_path);
+        let wide_verb = to_wide("open");
+
+        let result = unsafe {
+            ShellExecuteW(
+                HWND(0),
+                PCWSTR(wide_verb.as_ptr()),
+                PCWSTR(wide_app_path.as_ptr()),
+                PCWSTR(wide_file_path.as_ptr()),
+                PCWSTR::null(),
+                SW_SHOWNORMAL,
+            )
+        };
+
+        if result.0 as isize > 32 {
+            Ok(())
+        } else {
+            Err(i18n::t_with(
+                "error.shell_execute_open_failed",
+                &[("file", file_path), ("app", app_path)],
+            ))
+        }
+    }
+
+    fn process_matches_bundle(executable_path: &str, bundle_name: &str) -> bool {
+        let normalized_path = executable_path.trim().to_ascii_lowercase();
+        if normalized_path.is_empty() || normalized_path.contains("backend-sidecar") {
+            return false;
+        }
+
+        Path::new(&normalized_path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem == bundle_name.to_ascii_lowercase())
+    }
+}
+
+/// Resolves the registered handler executable for a file's extension. `.<ext>` keys only
+/// ever name a ProgID (or, under `HKEY_CURRENT_USER`, a per-user override) — the actual
+/// `shell\open\command` lives under that ProgID's own key, not under `.<ext>` itself — so
+/// this follows the indirection: the user's `UserChoice` override first, then the
+/// system-wide default ProgID under `HKEY_CLASSES_ROOT`.
+fn registered_handler_exe(file_path: &Path) -> Option<String> {
+    let extension = file_path.extension()?.to_str()?;
+    let prog_id = user_choice_prog_id(extension).or_else(|| default_prog_id(extension))?;
+    let command_line = read_registry_value(HKEY_CLASSES_ROOT, &format!(r"{prog_id}\shell\open\command"), None)?;
+    extract_executable_from_command(&command_line)
+}
+
+/// Reads the per-user file association override at
+/// `HKEY_CURRENT_USER\...\Explorer\FileExts\.<ext>\UserChoice`, which takes precedence over
+/// the system-wide default ProgID when present.
+fn user_choice_prog_id(extension: &str) -> Option<String> {
+    let subkey =
+        format!(r"Software\Microsoft\Windows\CurrentVersion\Explorer\FileExts\.{extension}\UserChoice");
+    read_registry_value(HKEY_CURRENT_USER, &subkey, Some("ProgId"))
+}
+
+/// Reads the system-wide default ProgID for an extension from `HKEY_CLASSES_ROOT\.<ext>`'s
+/// unnamed (default) value.
+fn default_prog_id(extension: &str) -> Option<String> {
+    read_registry_value(HKEY_CLASSES_ROOT, &format!(".{extension}"), None)
+}
+
+/// Extracts the executable path from a `shell\open\command` value, which is either a
+/// quoted path (optionally followed by `%1`-style arguments) or an unquoted path up to the
+/// first space.
+fn extract_executable_from_command(command_line: &str) -> Option<String> {
+    let trimmed = command_line.trim();
+    if let Some(rest) = trimmed.strip_prefix('"') {
+        return rest.find('"').map(|end| rest[..end].to_string());
+    }
+
+    trimmed.split_whitespace().next().map(str::to_string)
+}
+
+fn read_registry_value(root: HKEY, subkey: &str, value_name: Option<&str>) -> Option<String> {
+    let wide_subkey = to_wide(subkey);
+    let wide_value_name = value_name.map(to_wide);
+    let value_name_ptr = match &wide_value_name {
+        Some(wide_value_name) => PCWSTR(wide_value_name.as_ptr()),
+        None => PCWSTR::null(),
+    };
+
+    let mut buffer = [0u16; 1024];
+    let mut buffer_len = (buffer.len() * std::mem::size_of::<u16>()) as u32;
+
+    let status = unsafe {
+        RegGetValueW(
+            root,
+            PCWSTR(wide_subkey.as_ptr()),
+            value_name_ptr,
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr().cast()),
+            Some(&mut buffer_len),
+        )
+    };
+
+    if status.is_err() {
+        return None;
+    }
+
+    let chars_written = (buffer_len as usize / std::mem::size_of::<u16>()).saturating_sub(1);
+    Some(String::from_utf16_lossy(&buffer[..chars_written]))
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .and_then(|found| found.to_str())
+        .map(|found| found.eq_ignore_ascii_case(extension))
+        .unwrap_or(false)
+}
+
+/// The Start Menu directories to scan: the current user's, then the all-users one.
+fn start_menu_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Ok(app_data) = env::var("APPDATA") {
+        dirs.push(Path::new(&app_data).join(r"Microsoft\Windows\Start Menu\Programs"));
+    }
+    if let Ok(program_data) = env::var("PROGRAMDATA") {
+        dirs.push(Path::new(&program_data).join(r"Microsoft\Windows\Start Menu\Programs"));
+    }
+
+    dirs
+}
+
+fn collect_shortcuts(directory: &Path, apps: &mut Vec<RegisteredApp>) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_shortcuts(&path, apps);
+            continue;
+        }
+        if !WindowsPlatform::is_valid_app_path(&path) {
+            continue;
+        }
+
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let Some(path_string) = path.to_str() else {
+            continue;
+        };
+
+        apps.push(RegisteredApp {
+            name: name.to_string(),
+            path: path_string.to_string(),
+        });
+    }
+}
+
+fn to_wide(value: &str) -> Vec<u16> {
+    OsStr::new(value).encode_wide().chain(std::iter::once(0)).collect()
+}
+
+/// Invokes `ShellExecuteW` with the `open` verb. Per the Win32 docs, a return value
+/// greater than 32 indicates success; anything else is an error code.
+fn shell_execute_open(path: &str) -> Result<(), String> {
+    let wide_path = to_wide(path);
+    let wide_verb = to_wide("open");
+
+    let result = unsafe {
+        ShellExecuteW(
+            HWND(0),
+            PCWSTR(wide_verb.as_ptr()),
+            PCWSTR(wide_path.as_ptr()),
+            PCWSTR::null(),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    if result.0 as isize > 32 {
+        Ok(())
+    } else {
+        Err(i18n::t_with("error.shell_execute_launch_failed", &[("path", path)]))
+    }
+}
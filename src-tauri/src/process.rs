@@ -0,0 +1,61 @@
+//! Running-process detection, backed by a single `sysinfo` snapshot taken once per poll
+//! and queried against for every registered app, instead of shelling out to `ps` and
+//! re-scanning its output for each app being checked.
+
+use crate::platform::{AppPlatform, CurrentPlatform};
+use sysinfo::{ProcessesToUpdate, System};
+
+/// An indexed snapshot of every running process's resolved executable path.
+pub struct ProcessIndex {
+    executable_paths: Vec<String>,
+}
+
+impl ProcessIndex {
+    /// Takes a fresh snapshot of the system's running processes.
+    pub fn capture() -> Self {
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::All, true);
+
+        let executable_paths = system
+            .processes()
+            .values()
+            .filter_map(|process| process.exe())
+            .filter_map(|path| path.to_str())
+            .map(str::to_string)
+            .collect();
+
+        Self { executable_paths }
+    }
+
+    #[cfg(test)]
+    fn from_paths(paths: &[&str]) -> Self {
+        Self {
+            executable_paths: paths.iter().map(|path| (*path).to_string()).collect(),
+        }
+    }
+
+    /// `true` if the snapshot contains a launchable (non-helper) process for the app
+    /// named `bundle_name`.
+    pub fn is_bundle_running(&self, bundle_name: &str) -> bool {
+        self.executable_paths
+            .iter()
+            .any(|path| CurrentPlatform::process_matches_bundle(path, bundle_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProcessIndex;
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn running_state_uses_matching_non_sidecar_processes_only() {
+        let processes = ProcessIndex::from_paths(&[
+            "/Applications/Dugout.app/Contents/MacOS/backend-sidecar",
+            "/Users/jordanheckler/companion/src-tauri/target/release/bundle/macos/companion.app/Contents/MacOS/app",
+        ]);
+
+        assert!(processes.is_bundle_running("Companion"));
+        assert!(!processes.is_bundle_running("Dugout"));
+    }
+}